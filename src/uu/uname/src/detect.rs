@@ -0,0 +1,193 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+// spell-checker:ignore (vars) cpuinfo sysctl sysctlbyname osreldate illumos
+
+//! Best-effort detection of the `-p` (processor type) and `-i` (hardware
+//! platform) fields. GNU `uname` reports both as "unknown" on Linux, but
+//! BSD/macOS expose meaningful values through `sysctl`, so we query the
+//! platform directly and let the caller fall back to "unknown".
+
+/// Detect the processor type (`-p`), or `None` when it cannot be determined.
+pub fn processor() -> Option<String> {
+    #[cfg(target_os = "linux")]
+    {
+        processor_from_cpuinfo()
+    }
+    #[cfg(any(target_os = "macos", target_os = "freebsd"))]
+    {
+        sysctl_string("hw.machine")
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "freebsd")))]
+    {
+        None
+    }
+}
+
+/// Detect the hardware platform (`-i`), or `None` when it cannot be determined.
+pub fn hardware_platform() -> Option<String> {
+    #[cfg(target_os = "linux")]
+    {
+        // Linux has no notion of a distinct hardware platform.
+        None
+    }
+    #[cfg(any(target_os = "macos", target_os = "freebsd"))]
+    {
+        sysctl_string("hw.model")
+    }
+    #[cfg(any(target_os = "solaris", target_os = "illumos"))]
+    {
+        platform_sysinfo()
+    }
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "freebsd",
+        target_os = "solaris",
+        target_os = "illumos"
+    )))]
+    {
+        None
+    }
+}
+
+/// Detect the FreeBSD kernel version integer (`-K`, `__FreeBSD_version`).
+#[cfg(target_os = "freebsd")]
+pub fn kernel_version_int() -> Option<String> {
+    sysctl_int("kern.osreldate")
+}
+
+/// Detect the FreeBSD userland version (`-U`).
+#[cfg(target_os = "freebsd")]
+pub fn userland_version() -> Option<String> {
+    // getosreldate(3) honors the OSVERSION environment variable, which carries
+    // the userland's __FreeBSD_version and can differ from the running kernel's
+    // kern.osreldate (e.g. a new userland on an older kernel); when it is unset
+    // it falls back to the kern.osreldate sysctl, which we mirror here.
+    std::env::var("OSVERSION")
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .or_else(|| sysctl_int("kern.osreldate"))
+}
+
+/// Query illumos/Solaris `sysinfo(2)` for the `SI_PLATFORM` string (e.g.
+/// `i86pc` or `SUNW,Sun-Fire-V240`), which those systems report for `-i`.
+#[cfg(any(target_os = "solaris", target_os = "illumos"))]
+fn platform_sysinfo() -> Option<String> {
+    use std::os::raw::{c_char, c_int, c_long};
+
+    // SI_PLATFORM from <sys/systeminfo.h>.
+    const SI_PLATFORM: c_int = 513;
+
+    extern "C" {
+        fn sysinfo(command: c_int, buf: *mut c_char, count: c_long) -> c_int;
+    }
+
+    let mut buf = [0 as c_char; 257];
+    let ret = unsafe { sysinfo(SI_PLATFORM, buf.as_mut_ptr(), buf.len() as c_long) };
+    if ret < 0 {
+        return None;
+    }
+    let value = unsafe { std::ffi::CStr::from_ptr(buf.as_ptr()) }
+        .to_string_lossy()
+        .trim()
+        .to_string();
+    (!value.is_empty()).then_some(value)
+}
+
+#[cfg(target_os = "linux")]
+fn processor_from_cpuinfo() -> Option<String> {
+    let contents = std::fs::read_to_string("/proc/cpuinfo").ok()?;
+    for line in contents.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        if key.trim() == "model name" {
+            let value = value.trim();
+            if !value.is_empty() {
+                return Some(value.to_string());
+            }
+        }
+    }
+    None
+}
+
+#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+extern "C" {
+    fn sysctlbyname(
+        name: *const std::os::raw::c_char,
+        oldp: *mut std::os::raw::c_void,
+        oldlenp: *mut usize,
+        newp: *const std::os::raw::c_void,
+        newlen: usize,
+    ) -> std::os::raw::c_int;
+}
+
+/// Read a string-valued sysctl (e.g. `hw.machine`, `hw.model`) through the
+/// libc `sysctlbyname` API, returning `None` on any error or empty value.
+#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+fn sysctl_string(name: &str) -> Option<String> {
+    use std::os::raw::c_void;
+
+    let cname = std::ffi::CString::new(name).ok()?;
+    // First call with a null buffer to discover the value's length.
+    let mut len: usize = 0;
+    let ret = unsafe {
+        sysctlbyname(
+            cname.as_ptr(),
+            std::ptr::null_mut(),
+            &mut len,
+            std::ptr::null(),
+            0,
+        )
+    };
+    if ret != 0 || len == 0 {
+        return None;
+    }
+    let mut buf = vec![0u8; len];
+    let ret = unsafe {
+        sysctlbyname(
+            cname.as_ptr(),
+            buf.as_mut_ptr() as *mut c_void,
+            &mut len,
+            std::ptr::null(),
+            0,
+        )
+    };
+    if ret != 0 {
+        return None;
+    }
+    buf.truncate(len);
+    while buf.last() == Some(&0) {
+        buf.pop();
+    }
+    let value = String::from_utf8_lossy(&buf).trim().to_string();
+    (!value.is_empty()).then_some(value)
+}
+
+/// Read an integer-valued sysctl (e.g. `kern.osreldate`) through the libc
+/// `sysctlbyname` API, returning its decimal string form.
+#[cfg(target_os = "freebsd")]
+fn sysctl_int(name: &str) -> Option<String> {
+    use std::os::raw::{c_int, c_void};
+
+    let cname = std::ffi::CString::new(name).ok()?;
+    let mut value: c_int = 0;
+    let mut len = std::mem::size_of::<c_int>();
+    let ret = unsafe {
+        sysctlbyname(
+            cname.as_ptr(),
+            &mut value as *mut c_int as *mut c_void,
+            &mut len,
+            std::ptr::null(),
+            0,
+        )
+    };
+    if ret != 0 {
+        return None;
+    }
+    Some(value.to_string())
+}
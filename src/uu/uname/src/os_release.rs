@@ -0,0 +1,163 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+// spell-checker:ignore (vars) VERSION_ID VERSION_CODENAME PRETTY_NAME
+
+//! Minimal parser for the freedesktop `os-release` file.
+//!
+//! On Linux the kernel only knows it is "GNU/Linux"; the human-readable
+//! distribution name lives in `/etc/os-release` (with `/usr/lib/os-release`
+//! as the vendor fallback). The file is a sequence of `KEY=VALUE` shell-style
+//! assignments, so we parse just enough of that grammar to recover the fields
+//! `uname` cares about.
+
+use std::fs;
+use std::path::Path;
+
+/// The subset of `os-release` fields surfaced by `uname`.
+#[derive(Debug, Default, Clone)]
+pub struct OsRelease {
+    pub name: Option<String>,
+    pub version_id: Option<String>,
+    pub version_codename: Option<String>,
+    pub id: Option<String>,
+    pub pretty_name: Option<String>,
+}
+
+/// The field names accepted by `--os-release`.
+pub const FIELDS: [&str; 5] = ["NAME", "VERSION_ID", "VERSION_CODENAME", "ID", "PRETTY_NAME"];
+
+/// Return `true` when `name` (case-insensitive) is a recognized `os-release`
+/// field, so callers can reject an unknown `--os-release=FIELD` argument.
+pub fn is_valid_field(name: &str) -> bool {
+    FIELDS.contains(&name.to_ascii_uppercase().as_str())
+}
+
+impl OsRelease {
+    /// Load the distribution's `os-release`, preferring `/etc/os-release` and
+    /// falling back to `/usr/lib/os-release`. Returns `None` when neither file
+    /// exists or can be read, so callers can fall back to `osname()`.
+    pub fn load() -> Option<Self> {
+        for path in ["/etc/os-release", "/usr/lib/os-release"] {
+            if let Some(release) = Self::from_file(path) {
+                return Some(release);
+            }
+        }
+        None
+    }
+
+    fn from_file<P: AsRef<Path>>(path: P) -> Option<Self> {
+        fs::read_to_string(path).ok().map(|s| Self::parse(&s))
+    }
+
+    /// Parse the contents of an `os-release` file.
+    pub fn parse(contents: &str) -> Self {
+        let mut release = Self::default();
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = unquote(value.trim());
+            match key.trim() {
+                "NAME" => release.name = Some(value),
+                "VERSION_ID" => release.version_id = Some(value),
+                "VERSION_CODENAME" => release.version_codename = Some(value),
+                "ID" => release.id = Some(value),
+                "PRETTY_NAME" => release.pretty_name = Some(value),
+                _ => {}
+            }
+        }
+        release
+    }
+
+    /// Return a named field, matching the keys accepted by `--os-release`.
+    /// The field name is case-insensitive; an unrecognized name yields `None`.
+    pub fn field(&self, name: &str) -> Option<&str> {
+        let field = match name.to_ascii_uppercase().as_str() {
+            "NAME" => &self.name,
+            "VERSION_ID" => &self.version_id,
+            "VERSION_CODENAME" => &self.version_codename,
+            "ID" => &self.id,
+            "PRETTY_NAME" => &self.pretty_name,
+            _ => return None,
+        };
+        field.as_deref()
+    }
+}
+
+/// Strip shell-style quoting from an `os-release` value. Unquoted values are
+/// returned verbatim; single-quoted values are taken literally; double-quoted
+/// values have their backslash escapes resolved.
+fn unquote(value: &str) -> String {
+    let bytes = value.as_bytes();
+    match bytes.first() {
+        Some(b'\'') if value.ends_with('\'') && value.len() >= 2 => {
+            value[1..value.len() - 1].to_string()
+        }
+        Some(b'"') if value.ends_with('"') && value.len() >= 2 => {
+            let inner = &value[1..value.len() - 1];
+            let mut out = String::with_capacity(inner.len());
+            let mut chars = inner.chars();
+            while let Some(c) = chars.next() {
+                if c == '\\' {
+                    match chars.next() {
+                        Some(next) => out.push(next),
+                        None => out.push('\\'),
+                    }
+                } else {
+                    out.push(c);
+                }
+            }
+            out
+        }
+        _ => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unquote_unquoted_value() {
+        assert_eq!(unquote("debian"), "debian");
+    }
+
+    #[test]
+    fn unquote_single_quoted_is_literal() {
+        assert_eq!(unquote(r"'a\nb'"), r"a\nb");
+    }
+
+    #[test]
+    fn unquote_double_quoted_resolves_escapes() {
+        assert_eq!(unquote(r#""Debian \"GNU\"/Linux""#), r#"Debian "GNU"/Linux"#);
+    }
+
+    #[test]
+    fn parse_ignores_comments_and_blank_lines() {
+        let contents = "# a comment\n\nID=ubuntu\nPRETTY_NAME=\"Ubuntu 22.04 LTS\"\n";
+        let release = OsRelease::parse(contents);
+        assert_eq!(release.id.as_deref(), Some("ubuntu"));
+        assert_eq!(release.pretty_name.as_deref(), Some("Ubuntu 22.04 LTS"));
+        assert_eq!(release.name, None);
+    }
+
+    #[test]
+    fn field_lookup_is_case_insensitive() {
+        let release = OsRelease::parse("VERSION_ID=22.04\n");
+        assert_eq!(release.field("version_id"), Some("22.04"));
+        assert_eq!(release.field("BOGUS"), None);
+    }
+
+    #[test]
+    fn valid_field_names() {
+        assert!(is_valid_field("pretty_name"));
+        assert!(!is_valid_field("bogus"));
+    }
+}
@@ -0,0 +1,95 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Synthesize a config.guess-style GNU system triplet (`cpu-vendor-os`) from
+//! the fields already collected in `PlatformInfo`, so users can identify the
+//! build target without shelling out to autotools' `config.guess` script.
+
+/// Build a canonical `cpu-vendor-os` triplet, e.g. `x86_64-pc-linux-gnu` or
+/// `aarch64-apple-darwin21`.
+pub fn canonical_triplet(machine: &str, sysname: &str, release: &str) -> String {
+    let cpu = normalize_cpu(machine);
+    let vendor = vendor(sysname, &cpu);
+    let os = os_field(sysname, release);
+    format!("{cpu}-{vendor}-{os}")
+}
+
+/// Normalize the machine name the way `config.guess` does (fold the 32-bit x86
+/// family to `i686`, `armv7l` and `armv6l` to `arm`, and leave anything else
+/// untouched).
+fn normalize_cpu(machine: &str) -> String {
+    match machine {
+        "i386" | "i486" | "i586" | "i686" => "i686".to_string(),
+        "armv7l" | "armv6l" => "arm".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Pick the vendor field: `pc` for Linux on x86, `apple` for Darwin, and
+/// `unknown` everywhere else.
+fn vendor(sysname: &str, cpu: &str) -> &'static str {
+    match sysname {
+        "Linux" if cpu == "i686" || cpu == "x86_64" => "pc",
+        "Darwin" => "apple",
+        _ => "unknown",
+    }
+}
+
+/// Build the OS field from the kernel name, appending the libc ABI suffix on
+/// Linux and the kernel release elsewhere (matching `config.guess`).
+fn os_field(sysname: &str, release: &str) -> String {
+    match sysname {
+        "Linux" => format!("linux{}", libc_abi()),
+        "Darwin" => format!("darwin{release}"),
+        other => format!("{}{release}", other.to_ascii_lowercase()),
+    }
+}
+
+/// The libc ABI suffix for the current build target.
+fn libc_abi() -> &'static str {
+    if cfg!(target_os = "android") {
+        "-android"
+    } else if cfg!(target_env = "musl") {
+        "-musl"
+    } else if cfg!(target_env = "gnu") {
+        "-gnu"
+    } else {
+        ""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_cpu_folds_x86_family() {
+        assert_eq!(normalize_cpu("i386"), "i686");
+        assert_eq!(normalize_cpu("i586"), "i686");
+        assert_eq!(normalize_cpu("i686"), "i686");
+    }
+
+    #[test]
+    fn normalize_cpu_folds_arm() {
+        assert_eq!(normalize_cpu("armv7l"), "arm");
+        assert_eq!(normalize_cpu("aarch64"), "aarch64");
+        assert_eq!(normalize_cpu("x86_64"), "x86_64");
+    }
+
+    #[test]
+    fn vendor_selection() {
+        assert_eq!(vendor("Linux", "x86_64"), "pc");
+        assert_eq!(vendor("Linux", "aarch64"), "unknown");
+        assert_eq!(vendor("Darwin", "aarch64"), "apple");
+    }
+
+    #[test]
+    fn canonical_triplet_for_darwin() {
+        assert_eq!(
+            canonical_triplet("arm64", "Darwin", "21"),
+            "arm64-apple-darwin21"
+        );
+    }
+}
@@ -8,15 +8,22 @@
 
 // last synced with: uname (GNU coreutils) 8.21
 
-// spell-checker:ignore (API) nodename osname sysname (options) mnrsv mnrsvo
+// spell-checker:ignore (API) nodename osname sysname (options) mnrsv mnrsvo (vars) microsoft osreldate
 
 use clap::{crate_version, Arg, ArgAction, Command};
 use platform_info::*;
 use uucore::{
-    error::{FromIo, UResult},
+    error::{FromIo, UResult, UUsageError},
     format_usage,
 };
 
+mod os_release;
+use os_release::OsRelease;
+mod triplet;
+use triplet::canonical_triplet;
+mod detect;
+mod wsl;
+
 const ABOUT: &str = r#"Print certain system information.
 With no OPTION, same as -s."#;
 const USAGE: &str = "{} [OPTION]...";
@@ -31,6 +38,14 @@ pub mod options {
     pub static PROCESSOR: &str = "processor";
     pub static HARDWARE_PLATFORM: &str = "hardware-platform";
     pub static OS: &str = "operating-system";
+    pub static OS_RELEASE: &str = "os-release";
+    pub static CANONICAL: &str = "canonical";
+    pub static WSL_COMPAT: &str = "wsl-compat";
+    pub static POSIX: &str = "posix";
+    #[cfg(target_os = "freebsd")]
+    pub static KERNEL_VERSION_INT: &str = "kernel-version-int";
+    #[cfg(target_os = "freebsd")]
+    pub static USERLAND: &str = "userland";
 }
 
 #[uucore::main]
@@ -41,6 +56,38 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         PlatformInfo::new().map_err_context(|| "failed to create PlatformInfo".to_string())?;
     let mut output = String::new();
 
+    // `--os-release[=FIELD]` prints a single parsed field and exits; it does
+    // not take part in the usual multi-field assembly below.
+    if let Some(field) = matches.get_one::<String>(options::OS_RELEASE) {
+        if !os_release::is_valid_field(field) {
+            return Err(UUsageError::new(
+                1,
+                format!("invalid os-release field: {field}"),
+            ));
+        }
+        let release = OsRelease::load().unwrap_or_default();
+        println!("{}", release.field(field).unwrap_or_default());
+        return Ok(());
+    }
+
+    // `--canonical` synthesizes a single GNU triplet and exits, like running
+    // `config.guess`.
+    if matches.get_flag(options::CANONICAL) {
+        println!(
+            "{}",
+            canonical_triplet(&uname.machine(), &uname.sysname(), &uname.release())
+        );
+        return Ok(());
+    }
+
+    // On WSL the kernel release carries a vendor suffix that breaks naive
+    // `major.minor.patch` parsing. Normalization is strictly opt-in via
+    // `--wsl-compat` so the default output stays GNU drop-in compatible; the
+    // raw and normalized forms are both available (without and with the flag).
+    let raw_release = uname.release();
+    let raw_version = uname.version();
+    let wsl_compat = matches.get_flag(options::WSL_COMPAT);
+
     let all = matches.get_flag(options::ALL);
     let kernel_name = matches.get_flag(options::KERNEL_NAME);
     let nodename = matches.get_flag(options::NODENAME);
@@ -51,6 +98,10 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     let hardware_platform = matches.get_flag(options::HARDWARE_PLATFORM);
     let os = matches.get_flag(options::OS);
 
+    // POSIX.2 restricts `-a` to `-mnrsv` (no `-o`, `-p`, or `-i`), which is how
+    // several BSD/illumos systems document the flag.
+    let posix = matches.get_flag(options::POSIX);
+
     let none = !(all
         || kernel_name
         || nodename
@@ -73,12 +124,19 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     }
 
     if kernel_release || all {
-        output.push_str(&uname.release());
+        // Only `-r` carries the WSL `major.minor.patch` triple; `version()`
+        // starts with a build-number marker (`#...`) with no leading numeric
+        // component, so it is never normalized.
+        if wsl_compat {
+            output.push_str(&wsl::normalize(&raw_release));
+        } else {
+            output.push_str(&raw_release);
+        }
         output.push(' ');
     }
 
     if kernel_version || all {
-        output.push_str(&uname.version());
+        output.push_str(&raw_version);
         output.push(' ');
     }
 
@@ -87,22 +145,56 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         output.push(' ');
     }
 
-    if os || all {
-        output.push_str(&uname.osname());
+    // GNU ordering places the processor (-p) and hardware platform (-i)
+    // fields before the operating-system name (-o). When explicitly requested
+    // they print "unknown" if undetectable, but under -a they are omitted
+    // entirely rather than printed as "unknown".
+    if processor {
+        output.push_str(&detect::processor().unwrap_or_else(|| "unknown".to_string()));
         output.push(' ');
+    } else if all && !posix {
+        // GNU `uname -a` omits -p when it would be "unknown". On Linux the
+        // slot is always "unknown" to GNU, so the detected /proc/cpuinfo model
+        // must not leak into `-a`; only BSD/macOS populate it here.
+        #[cfg(not(target_os = "linux"))]
+        if let Some(p) = detect::processor() {
+            output.push_str(&p);
+            output.push(' ');
+        }
     }
 
-    // This option is unsupported on modern Linux systems
-    // See: https://lists.gnu.org/archive/html/bug-coreutils/2005-09/msg00063.html
-    if processor {
-        output.push_str("unknown");
+    if hardware_platform {
+        output.push_str(&detect::hardware_platform().unwrap_or_else(|| "unknown".to_string()));
         output.push(' ');
+    } else if all && !posix {
+        if let Some(i) = detect::hardware_platform() {
+            output.push_str(&i);
+            output.push(' ');
+        }
     }
 
-    // This option is unsupported on modern Linux systems
-    // See: https://lists.gnu.org/archive/html/bug-coreutils/2005-09/msg00063.html
-    if hardware_platform {
-        output.push_str("unknown");
+    // FreeBSD-specific kernel (`-K`) and userland (`-U`) version integers; not
+    // part of `-a`.
+    #[cfg(target_os = "freebsd")]
+    if matches.get_flag(options::KERNEL_VERSION_INT) {
+        output.push_str(&detect::kernel_version_int().unwrap_or_else(|| "unknown".to_string()));
+        output.push(' ');
+    }
+
+    #[cfg(target_os = "freebsd")]
+    if matches.get_flag(options::USERLAND) {
+        output.push_str(&detect::userland_version().unwrap_or_else(|| "unknown".to_string()));
+        output.push(' ');
+    }
+
+    if os || (all && !posix) {
+        // Enrich `-o`/`-a` with the distribution's PRETTY_NAME when an
+        // os-release file is present, falling back to the kernel's generic
+        // osname() otherwise.
+        match OsRelease::load().and_then(|r| r.pretty_name) {
+            Some(pretty) => output.push_str(&pretty),
+            None => output.push_str(&uname.osname()),
+        }
         output.push(' ');
     }
 
@@ -112,7 +204,8 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
 }
 
 pub fn uu_app() -> Command {
-    Command::new(uucore::util_name())
+    #[allow(unused_mut)]
+    let mut cmd = Command::new(uucore::util_name())
         .version(crate_version!())
         .about(ABOUT)
         .override_usage(format_usage(USAGE))
@@ -187,4 +280,60 @@ pub fn uu_app() -> Command {
                 .action(ArgAction::SetTrue)
                 .hide(true),
         )
+        .arg(
+            Arg::new(options::OS_RELEASE)
+                .long(options::OS_RELEASE)
+                .help(
+                    "print a field from /etc/os-release (default PRETTY_NAME); \
+                valid fields are NAME, VERSION_ID, VERSION_CODENAME, ID, and \
+                PRETTY_NAME.",
+                )
+                .value_name("FIELD")
+                .num_args(0..=1)
+                .default_missing_value("PRETTY_NAME"),
+        )
+        .arg(
+            Arg::new(options::CANONICAL)
+                .short('c')
+                .long(options::CANONICAL)
+                .help("print the canonical GNU system triplet (cpu-vendor-os).")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new(options::WSL_COMPAT)
+                .long(options::WSL_COMPAT)
+                .help(
+                    "normalize the WSL kernel release string to its leading \
+                major.minor.patch triple (opt-in; the default output is left \
+                unchanged).",
+                )
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new(options::POSIX)
+                .long(options::POSIX)
+                .help("restrict -a to the POSIX.2 fields -mnrsv (omit -o/-p/-i).")
+                .action(ArgAction::SetTrue),
+        );
+
+    #[cfg(target_os = "freebsd")]
+    {
+        cmd = cmd
+            .arg(
+                Arg::new(options::KERNEL_VERSION_INT)
+                    .short('K')
+                    .long(options::KERNEL_VERSION_INT)
+                    .help("print the FreeBSD kernel version integer (__FreeBSD_version).")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new(options::USERLAND)
+                    .short('U')
+                    .long(options::USERLAND)
+                    .help("print the FreeBSD userland version.")
+                    .action(ArgAction::SetTrue),
+            );
+    }
+
+    cmd
 }
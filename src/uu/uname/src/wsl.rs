@@ -0,0 +1,53 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+// spell-checker:ignore (vars) microsoft
+
+//! Normalization for Windows Subsystem for Linux kernel strings.
+//!
+//! WSL reports a `release()` such as `5.15.90.1-microsoft-standard-WSL2`, which
+//! defeats naive `MAJOR.MINOR.PATCH` parsing. [`normalize`] extracts the
+//! leading numeric triple; the raw value remains the default `-r` output, so
+//! both forms stay reachable (without and with `--wsl-compat`).
+
+/// Extract the leading `major.minor.patch` triple, tolerating trailing
+/// non-numeric segments and the WSL vendor suffix. Returns the input unchanged
+/// when no leading numeric component is present.
+pub fn normalize(raw: &str) -> String {
+    let head: String = raw
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    let parts: Vec<&str> = head
+        .split('.')
+        .filter(|segment| !segment.is_empty())
+        .take(3)
+        .collect();
+    if parts.is_empty() {
+        raw.to_string()
+    } else {
+        parts.join(".")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_strips_wsl_suffix() {
+        assert_eq!(normalize("5.15.90.1-microsoft-standard-WSL2"), "5.15.90");
+    }
+
+    #[test]
+    fn normalize_passes_through_non_numeric() {
+        assert_eq!(normalize("#1 SMP PREEMPT"), "#1 SMP PREEMPT");
+    }
+
+    #[test]
+    fn normalize_handles_short_version() {
+        assert_eq!(normalize("6.1"), "6.1");
+    }
+}